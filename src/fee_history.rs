@@ -0,0 +1,230 @@
+use async_trait::async_trait;
+use fuels::accounts::provider::Provider;
+use fuels::client::{PageDirection, PaginationRequest};
+use fuels::types::tx_status::TxStatus;
+
+use crate::gas_oracle::{GasCategory, GasOracle};
+use crate::retry::{with_retry, RetryConfig};
+use crate::BenchmarkError;
+
+/// One block's contribution to the fee history.
+///
+/// `price` is the block's effective gas price (total fees / total gas over its
+/// transactions) and `gas_used_ratio` is `gas_used / gas_limit`, so callers can
+/// chart both price and congestion over time.
+#[derive(Debug, Clone, Copy)]
+pub struct FeeSample {
+    pub block_height: u32,
+    pub price: u64,
+    pub gas_used_ratio: f64,
+}
+
+/// Per-block prices and congestion for the last N blocks, newest first.
+#[derive(Debug, Clone, Default)]
+pub struct FeeHistory {
+    pub samples: Vec<FeeSample>,
+}
+
+impl FeeHistory {
+    /// The raw `(block_height, price, gas_used_ratio)` tuples, for charting
+    /// congestion over time.
+    pub fn raw(&self) -> Vec<(u32, u64, f64)> {
+        self.samples
+            .iter()
+            .map(|s| (s.block_height, s.price, s.gas_used_ratio))
+            .collect()
+    }
+
+    /// Price at the percentile associated with `category`.
+    ///
+    /// Returns [`BenchmarkError::GasPriceFetchError`] when there are no usable
+    /// blocks.
+    pub fn estimate(&self, category: GasCategory) -> Result<u64, BenchmarkError> {
+        let mut prices: Vec<u64> = self.samples.iter().map(|s| s.price).collect();
+        if prices.is_empty() {
+            return Err(BenchmarkError::GasPriceFetchError(
+                "no usable blocks in fee history".to_string(),
+            ));
+        }
+        prices.sort_unstable();
+
+        let pct = percentile(category);
+        let idx = (((prices.len() - 1) as f64) * pct).round() as usize;
+        Ok(prices[idx])
+    }
+}
+
+/// Percentile used for each category, following the ethers gas-oracle tiers.
+fn percentile(category: GasCategory) -> f64 {
+    match category {
+        GasCategory::Slow => 0.20,
+        GasCategory::Standard => 0.50,
+        GasCategory::Fast => 0.80,
+        GasCategory::Fastest => 0.95,
+    }
+}
+
+/// Fetch the last `n_blocks` blocks and derive per-block price and congestion.
+///
+/// Blocks whose gas limit is zero are skipped; if none remain the call returns
+/// [`BenchmarkError::GasPriceFetchError`].
+pub async fn get_fee_history(
+    provider: &Provider,
+    n_blocks: u32,
+) -> Result<FeeHistory, BenchmarkError> {
+    let retry = RetryConfig::default();
+
+    let params = with_retry(&retry, || async {
+        provider
+            .consensus_parameters()
+            .await
+            .map_err(|e| BenchmarkError::GasPriceFetchError(e.to_string()))
+    })
+    .await?;
+    let gas_limit = params.block_gas_limit();
+
+    let request = PaginationRequest {
+        cursor: None,
+        results: n_blocks as i32,
+        direction: PageDirection::Backward,
+    };
+    let blocks = with_retry(&retry, || {
+        let request = request.clone();
+        async move {
+            provider
+                .get_blocks(request)
+                .await
+                .map_err(|e| BenchmarkError::GasPriceFetchError(e.to_string()))
+        }
+    })
+    .await?;
+
+    let mut samples = Vec::new();
+    for block in blocks.results {
+        // A zero gas limit yields no meaningful congestion ratio; skip it.
+        if gas_limit == 0 {
+            continue;
+        }
+
+        let (gas_used, total_fee) = block_totals(provider, &block, &retry).await?;
+        let price = if gas_used == 0 { 0 } else { total_fee / gas_used };
+
+        samples.push(FeeSample {
+            block_height: block.header.height,
+            price,
+            gas_used_ratio: gas_used as f64 / gas_limit as f64,
+        });
+    }
+
+    if samples.is_empty() {
+        return Err(BenchmarkError::GasPriceFetchError(
+            "fee history contained no usable blocks".to_string(),
+        ));
+    }
+
+    Ok(FeeHistory { samples })
+}
+
+/// Sum the gas used and fees paid across a block's successful transactions.
+async fn block_totals(
+    provider: &Provider,
+    block: &fuels::types::block::Block,
+    retry: &RetryConfig,
+) -> Result<(u64, u64), BenchmarkError> {
+    let mut gas_used = 0u64;
+    let mut total_fee = 0u64;
+
+    for tx_id in &block.transactions {
+        let tx = with_retry(retry, || async {
+            provider
+                .get_transaction_by_id(tx_id)
+                .await
+                .map_err(|e| BenchmarkError::TransactionFetchError(e.to_string()))
+        })
+        .await?;
+
+        if let Some(tx) = tx {
+            if let TxStatus::Success(success) = tx.status {
+                gas_used = gas_used.saturating_add(success.total_gas);
+                total_fee = total_fee.saturating_add(success.total_fee);
+            }
+        }
+    }
+
+    Ok((gas_used, total_fee))
+}
+
+/// A [`GasOracle`](crate::gas_oracle::GasOracle) that derives tiered prices
+/// from recent block activity rather than a single instantaneous value.
+pub struct FeeHistoryOracle {
+    provider: Provider,
+    n_blocks: u32,
+}
+
+impl FeeHistoryOracle {
+    pub fn new(provider: Provider, n_blocks: u32) -> Self {
+        Self { provider, n_blocks }
+    }
+
+    /// Fetch the raw history underpinning the estimates, for charting.
+    pub async fn history(&self) -> Result<FeeHistory, BenchmarkError> {
+        get_fee_history(&self.provider, self.n_blocks).await
+    }
+}
+
+#[async_trait]
+impl GasOracle for FeeHistoryOracle {
+    async fn fetch(&self, category: GasCategory) -> Result<u64, BenchmarkError> {
+        self.history().await?.estimate(category)
+    }
+
+    /// Sample the history once and estimate every category from it, avoiding a
+    /// full block sweep per category.
+    async fn fetch_all(&self) -> Result<Vec<(GasCategory, u64)>, BenchmarkError> {
+        let history = self.history().await?;
+        GasCategory::ALL
+            .iter()
+            .map(|&c| history.estimate(c).map(|price| (c, price)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(prices: &[u64]) -> FeeHistory {
+        FeeHistory {
+            samples: prices
+                .iter()
+                .enumerate()
+                .map(|(i, &price)| FeeSample {
+                    block_height: i as u32,
+                    price,
+                    gas_used_ratio: 0.5,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn estimate_picks_per_tier_percentile() {
+        // sorted: [10,20,30,40,50,60,70,80,90,100], idx = round(9 * pct).
+        let h = history(&[100, 50, 30, 80, 20, 60, 40, 90, 10, 70]);
+        assert_eq!(h.estimate(GasCategory::Slow).unwrap(), 30); // 9*0.20=1.8 -> 2
+        assert_eq!(h.estimate(GasCategory::Standard).unwrap(), 60); // 9*0.50=4.5 -> 5
+        assert_eq!(h.estimate(GasCategory::Fast).unwrap(), 80); // 9*0.80=7.2 -> 7
+        assert_eq!(h.estimate(GasCategory::Fastest).unwrap(), 100); // 9*0.95=8.55 -> 9
+    }
+
+    #[test]
+    fn raw_exposes_height_price_and_ratio() {
+        let h = history(&[10, 20]);
+        assert_eq!(h.raw(), vec![(0, 10, 0.5), (1, 20, 0.5)]);
+    }
+
+    #[test]
+    fn estimate_errors_on_empty_history() {
+        assert!(FeeHistory::default().estimate(GasCategory::Standard).is_err());
+    }
+}
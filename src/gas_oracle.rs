@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use fuels::accounts::provider::Provider;
+use log::info;
+
+use crate::retry::{with_retry, RetryConfig};
+use crate::BenchmarkError;
+
+/// Price tier requested from a [`GasOracle`].
+///
+/// Mirrors the tiers exposed by the ethers gas-oracle middleware so callers
+/// can trade a conservative price (longer inclusion time) against an
+/// aggressive one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasCategory {
+    Slow,
+    Standard,
+    Fast,
+    Fastest,
+}
+
+impl GasCategory {
+    /// All categories, from cheapest to fastest.
+    pub const ALL: [GasCategory; 4] = [
+        GasCategory::Slow,
+        GasCategory::Standard,
+        GasCategory::Fast,
+        GasCategory::Fastest,
+    ];
+}
+
+/// A source of gas-price estimates.
+///
+/// Implementations fetch a price for a requested [`GasCategory`]; a
+/// [`ProviderOracle`] simply echoes the node's instantaneous price, while a
+/// [`FeeHistoryOracle`](crate::fee_history::FeeHistoryOracle) derives tiered
+/// prices from recent blocks.
+#[async_trait]
+pub trait GasOracle {
+    async fn fetch(&self, category: GasCategory) -> Result<u64, BenchmarkError>;
+
+    /// Prices for every category.
+    ///
+    /// The default fetches each category independently; oracles whose backing
+    /// fetch is expensive (e.g. the
+    /// [`FeeHistoryOracle`](crate::fee_history::FeeHistoryOracle)) override this
+    /// to sample once and estimate all tiers from that single snapshot.
+    async fn fetch_all(&self) -> Result<Vec<(GasCategory, u64)>, BenchmarkError> {
+        let mut out = Vec::with_capacity(GasCategory::ALL.len());
+        for category in GasCategory::ALL {
+            out.push((category, self.fetch(category).await?));
+        }
+        Ok(out)
+    }
+}
+
+/// A [`GasOracle`] backed by [`Provider::latest_gas_price`].
+///
+/// The node only reports a single instantaneous price, so every category
+/// resolves to the same value.
+pub struct ProviderOracle {
+    provider: Provider,
+    retry: RetryConfig,
+}
+
+impl ProviderOracle {
+    pub fn new(provider: Provider) -> Self {
+        Self {
+            provider,
+            retry: RetryConfig::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl GasOracle for ProviderOracle {
+    async fn fetch(&self, _category: GasCategory) -> Result<u64, BenchmarkError> {
+        with_retry(&self.retry, || async {
+            self.provider
+                .latest_gas_price()
+                .await
+                .map(|p| p.gas_price)
+                .map_err(|e| BenchmarkError::GasPriceFetchError(e.to_string()))
+        })
+        .await
+    }
+}
+
+/// Log the price reported for every [`GasCategory`] by the given oracle.
+pub async fn log_all_categories(oracle: &dyn GasOracle) -> Result<(), BenchmarkError> {
+    for (category, price) in oracle.fetch_all().await? {
+        info!("Gas price ({:?}): {}", category, price);
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use fuels::accounts::provider::Provider;
+use fuels::client::{PageDirection, PaginationRequest};
+use fuels::types::transaction_response::TransactionResponse;
+
+use crate::BenchmarkError;
+
+/// A reachable node's health snapshot.
+///
+/// Distinguishes "endpoint failed" from "data not yet present": the probe only
+/// succeeds when the node answers, and reports the height of its latest block.
+///
+/// `syncing` is `Some(true)`/`Some(false)` when the node reports a sync state
+/// and `None` when it does not expose one, so callers never mistake "unknown"
+/// for "fully synced."
+#[derive(Debug, Clone)]
+pub struct NodeHealth {
+    pub reachable: bool,
+    pub latest_block_height: u32,
+    pub syncing: Option<bool>,
+}
+
+/// Fetch the most recent transaction, if any.
+///
+/// Returns `Ok(None)` when the node is reachable but has no matching
+/// transaction, and an error only when the fetch itself fails.
+pub async fn fetch_latest_transaction(
+    provider: &Provider,
+) -> Result<Option<TransactionResponse>, BenchmarkError> {
+    let p_r = PaginationRequest {
+        cursor: None,
+        results: 1,
+        direction: PageDirection::Backward,
+    };
+
+    let transactions = provider
+        .get_transactions(p_r)
+        .await
+        .map_err(|e| BenchmarkError::TransactionFetchError(e.to_string()))?;
+
+    Ok(transactions.results.into_iter().next())
+}
+
+/// Probe an endpoint's liveness, bundling reachability and the latest block
+/// height.
+///
+/// Succeeding means the node answered; the returned height is genuine (genesis
+/// height 0 included), so callers can tell a live node from a dead endpoint
+/// without conflating a real height with "no block present."
+pub async fn probe_liveness(provider: &Provider) -> Result<NodeHealth, BenchmarkError> {
+    let chain_info = provider
+        .chain_info()
+        .await
+        .map_err(|e| BenchmarkError::BlockHeightFetchError(e.to_string()))?;
+
+    Ok(NodeHealth {
+        reachable: true,
+        latest_block_height: chain_info.latest_block.header.height,
+        // fuel-core's GraphQL chain info does not expose a sync flag, so report
+        // it as unknown rather than inventing a value.
+        syncing: None,
+    })
+}
@@ -2,13 +2,26 @@ use std::time::Instant;
 use std::str::FromStr;
 
 use fuels::accounts::{provider::Provider, wallet::WalletUnlocked};
-use fuels::client::{PageDirection, PaginationRequest};
 use fuels::types::ContractId;
 use spark_market_sdk::SparkMarketContract;
 use thiserror::Error;
 use dotenv::dotenv;
 use log::info;
 
+mod gas_oracle;
+mod fee_history;
+mod retry;
+mod pool;
+mod runner;
+mod liveness;
+
+use fee_history::FeeHistoryOracle;
+use gas_oracle::{log_all_categories, GasOracle, ProviderOracle};
+use liveness::{fetch_latest_transaction, probe_liveness};
+use pool::{PoolMode, ProviderPool};
+use retry::{with_retry, RetryConfig};
+use runner::{BenchmarkRunner, ReportFormat};
+
 
 #[derive(Debug, Error)]
 pub enum BenchmarkError {
@@ -48,70 +61,88 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     let contract_id = "contract_id";
 
+    let format = parse_format().unwrap_or(ReportFormat::Json);
+
     info!("Starting benchmarks...");
 
-    for url in &provider_urls {
-        info!("\nBenchmarking provider: {}", url);
+    let pool = ProviderPool::new(
+        provider_urls.iter().map(|u| u.to_string()).collect(),
+        PoolMode::Race,
+    );
 
-        
-        match benchmark_node(url).await {
-            Ok(duration) => info!("Node Request: Response Time: {:.2?}", duration),
-            Err(e) => info!("Node Request: Error: {}", e),
-        }
+    let (provider, url) = pool.healthy_provider().await?;
+    info!("\nBenchmarking provider: {}", url);
 
-        
-        /*
-        match benchmark_contract(url, &mnemonic, contract_id).await {
-            Ok(duration) => info!("Contract Request: Response Time: {:.2?}", duration),
-            Err(e) => info!("Contract Request: Error: {}", e),
-        }*/
+    let oracle: Box<dyn GasOracle> = match std::env::var("GAS_ORACLE").as_deref() {
+        Ok("fee-history") => Box::new(FeeHistoryOracle::new(provider.clone(), 10)),
+        _ => Box::new(ProviderOracle::new(provider.clone())),
+    };
+    match benchmark_node(&url, &provider, oracle.as_ref()).await {
+        Ok(duration) => info!("Node Request: Response Time: {:.2?}", duration),
+        Err(e) => info!("Node Request: Error: {}", e),
+    }
+
+    let report = BenchmarkRunner::new(20).run(&url, &provider).await;
+    match report.render(format) {
+        Ok(rendered) => println!("{}", rendered),
+        Err(e) => info!("Report Error: {}", e),
     }
 
+
+    /*
+    match benchmark_contract(&url, &mnemonic, contract_id).await {
+        Ok(duration) => info!("Contract Request: Response Time: {:.2?}", duration),
+        Err(e) => info!("Contract Request: Error: {}", e),
+    }*/
+
     Ok(())
 }
 
 
-async fn benchmark_node(url: &str) -> Result<std::time::Duration, BenchmarkError> {
+/// Parse a `--format <json|csv>` flag from the process arguments.
+fn parse_format() -> Option<ReportFormat> {
+    let mut args = std::env::args();
+    while let Some(arg) = args.next() {
+        if arg == "--format" {
+            if let Some(value) = args.next() {
+                return value.parse().ok();
+            }
+        }
+    }
+    None
+}
+
+async fn benchmark_node(
+    url: &str,
+    provider: &Provider,
+    oracle: &dyn GasOracle,
+) -> Result<std::time::Duration, BenchmarkError> {
     let start_time = Instant::now();
+    let retry = RetryConfig::default();
 
-    
-    info!("Connecting to node {:?}", url);
-    let provider = Provider::connect(url)
-        .await
-        .map_err(|e| BenchmarkError::ProviderConnectionError(e.to_string()))?;
-    info!("Connected");
+    info!("Benchmarking node {:?}", url);
+
+    info!("Probing node liveness...");
+    let health = with_retry(&retry, || async { probe_liveness(provider).await }).await?;
+    info!(
+        "Reachable: {}, block height: {}, syncing: {:?}",
+        health.reachable, health.latest_block_height, health.syncing
+    );
 
-    
-    info!("Trying to get last block height...");
-    let lbh = provider
-        .latest_block_height()
-        .await
-        .map_err(|e| BenchmarkError::BlockHeightFetchError(e.to_string()))?;
-    info!("Block height: {:?}", lbh);
 
-    
     info!("Trying to get latest gas price...");
-    let gas_price = provider
-        .latest_gas_price()
-        .await
-        .map_err(|e| BenchmarkError::GasPriceFetchError(e.to_string()))?;
-    info!("Latest gas price: {:?}", gas_price);
+    log_all_categories(oracle).await?;
+
 
-    
     info!("Trying to fetch the latest transaction...");
-    let p_r = PaginationRequest {
-        cursor: None,
-        results: 10,
-        direction: PageDirection::Backward
-    };
-    let transactions = provider.get_transactions(p_r)
-        .await
-        .map_err(|e| BenchmarkError::TransactionFetchError(e.to_string()))?;
-    
-    if let Some(latest_tx) = transactions.results.first() {
-        info!("Latest transaction: {:?}", latest_tx.status);
-    } else {
-        info!("No transactions found in the latest block.");
+    let latest_tx = with_retry(&retry, || async {
+        fetch_latest_transaction(provider).await
+    })
+    .await?;
+
+    match latest_tx {
+        Some(tx) => info!("Latest transaction: {:?}", tx.status),
+        None => info!("No transactions found."),
     }
 
     Ok(start_time.elapsed())
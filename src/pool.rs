@@ -0,0 +1,101 @@
+use fuels::accounts::provider::Provider;
+use futures::future::select_ok;
+use log::{info, warn};
+
+use crate::retry::{with_retry, RetryConfig};
+use crate::BenchmarkError;
+
+/// How a [`ProviderPool`] picks an endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolMode {
+    /// Try endpoints in order, advancing on connection failure.
+    Failover,
+    /// Connect to every endpoint concurrently and keep the first healthy one.
+    Race,
+}
+
+/// A set of interchangeable provider endpoints with failover and racing.
+///
+/// Both benchmark entry points can ask the pool for a live, low-latency node
+/// instead of failing when a single endpoint is down.
+pub struct ProviderPool {
+    urls: Vec<String>,
+    mode: PoolMode,
+    retry: RetryConfig,
+}
+
+impl ProviderPool {
+    pub fn new(urls: Vec<String>, mode: PoolMode) -> Self {
+        Self {
+            urls,
+            mode,
+            retry: RetryConfig::default(),
+        }
+    }
+
+    /// Return a connected, healthy [`Provider`] together with the winning URL.
+    ///
+    /// Returns [`BenchmarkError::ProviderConnectionError`] when no endpoint
+    /// answers.
+    pub async fn healthy_provider(&self) -> Result<(Provider, String), BenchmarkError> {
+        match self.mode {
+            PoolMode::Failover => self.failover().await,
+            PoolMode::Race => self.race().await,
+        }
+    }
+
+    async fn failover(&self) -> Result<(Provider, String), BenchmarkError> {
+        let mut last_error = None;
+        for url in &self.urls {
+            match self.try_healthy(url).await {
+                Ok(provider) => return Ok((provider, url.clone())),
+                Err(e) => {
+                    warn!("Endpoint {} unhealthy ({}); trying next", url, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            BenchmarkError::ProviderConnectionError("no endpoints configured".to_string())
+        }))
+    }
+
+    async fn race(&self) -> Result<(Provider, String), BenchmarkError> {
+        if self.urls.is_empty() {
+            return Err(BenchmarkError::ProviderConnectionError(
+                "no endpoints configured".to_string(),
+            ));
+        }
+
+        let attempts = self.urls.iter().map(|url| {
+            let url = url.clone();
+            Box::pin(async move {
+                let provider = self.try_healthy(&url).await?;
+                Ok::<_, BenchmarkError>((provider, url))
+            })
+        });
+
+        select_ok(attempts).await.map(|(winner, _rest)| winner)
+    }
+
+    /// Connect to a single endpoint and confirm it serves a block height.
+    async fn try_healthy(&self, url: &str) -> Result<Provider, BenchmarkError> {
+        let provider = with_retry(&self.retry, || async {
+            Provider::connect(url)
+                .await
+                .map_err(|e| BenchmarkError::ProviderConnectionError(e.to_string()))
+        })
+        .await?;
+
+        with_retry(&self.retry, || async {
+            provider
+                .latest_block_height()
+                .await
+                .map_err(|e| BenchmarkError::BlockHeightFetchError(e.to_string()))
+        })
+        .await?;
+
+        info!("Endpoint {} healthy", url);
+        Ok(provider)
+    }
+}
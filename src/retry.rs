@@ -0,0 +1,148 @@
+use std::future::Future;
+use std::time::Duration;
+
+use log::warn;
+
+use crate::BenchmarkError;
+
+/// Tuning for [`with_retry`].
+///
+/// Delays grow as `min(base_delay * 2^attempt, max_delay)`, with up to
+/// `delay / 2` of random jitter added when `jitter` is set to spread out
+/// retries against a flaky endpoint.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Backoff delay for the given zero-based attempt, before jitter.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt);
+        self.base_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+/// Run `op` with exponential backoff, retrying on [`BenchmarkError`].
+///
+/// The closure is invoked up to `config.max_attempts` times; the last error is
+/// returned once attempts are exhausted.
+pub async fn with_retry<T, F, Fut>(
+    config: &RetryConfig,
+    mut op: F,
+) -> Result<T, BenchmarkError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, BenchmarkError>>,
+{
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                attempt += 1;
+                if attempt >= config.max_attempts {
+                    return Err(e);
+                }
+
+                let mut delay = config.backoff(attempt - 1);
+                if config.jitter {
+                    delay += jitter(delay);
+                }
+                warn!(
+                    "Attempt {} failed ({}); retrying in {:.2?}",
+                    attempt, e, delay
+                );
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// Random jitter in `[0, delay / 2]`.
+fn jitter(delay: Duration) -> Duration {
+    let half = delay / 2;
+    if half.is_zero() {
+        return Duration::ZERO;
+    }
+    let nanos = half.as_nanos() as u64;
+    Duration::from_nanos(rand::random::<u64>() % (nanos + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn config(max_attempts: u32) -> RetryConfig {
+        RetryConfig {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(8),
+            jitter: false,
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_and_caps() {
+        let cfg = RetryConfig {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            ..config(5)
+        };
+        assert_eq!(cfg.backoff(0), Duration::from_millis(100));
+        assert_eq!(cfg.backoff(1), Duration::from_millis(200));
+        assert_eq!(cfg.backoff(2), Duration::from_millis(400));
+        assert_eq!(cfg.backoff(3), Duration::from_millis(800));
+        assert_eq!(cfg.backoff(4), Duration::from_millis(1000)); // capped
+    }
+
+    #[tokio::test]
+    async fn retries_until_attempts_exhausted() {
+        let calls = Cell::new(0);
+        let result: Result<(), BenchmarkError> = with_retry(&config(3), || {
+            calls.set(calls.get() + 1);
+            async { Err(BenchmarkError::ProviderConnectionError("boom".to_string())) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn stops_on_first_success() {
+        let calls = Cell::new(0);
+        let result = with_retry(&config(5), || {
+            let attempt = calls.get() + 1;
+            calls.set(attempt);
+            async move {
+                if attempt < 2 {
+                    Err(BenchmarkError::ProviderConnectionError("boom".to_string()))
+                } else {
+                    Ok(42)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls.get(), 2);
+    }
+}
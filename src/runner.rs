@@ -0,0 +1,305 @@
+use std::time::{Duration, Instant};
+
+use fuels::accounts::provider::Provider;
+use fuels::client::{PageDirection, PaginationRequest};
+use serde::Serialize;
+
+use crate::BenchmarkError;
+
+/// Output format for a [`BenchmarkReport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+impl std::str::FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(ReportFormat::Json),
+            "csv" => Ok(ReportFormat::Csv),
+            other => Err(format!("unknown report format: {}", other)),
+        }
+    }
+}
+
+/// Latency statistics for a single operation over many samples.
+#[derive(Debug, Clone, Serialize)]
+pub struct OpStats {
+    pub op: String,
+    pub samples: usize,
+    pub successes: usize,
+    pub errors: usize,
+    pub success_rate: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+}
+
+impl OpStats {
+    fn from_durations(op: &str, durations: &[Duration], errors: usize) -> Self {
+        let samples = durations.len() + errors;
+        let successes = durations.len();
+
+        let mut millis: Vec<f64> = durations.iter().map(duration_ms).collect();
+        millis.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let min_ms = millis.first().copied().unwrap_or(0.0);
+        let max_ms = millis.last().copied().unwrap_or(0.0);
+        let mean_ms = if millis.is_empty() {
+            0.0
+        } else {
+            millis.iter().sum::<f64>() / millis.len() as f64
+        };
+
+        OpStats {
+            op: op.to_string(),
+            samples,
+            successes,
+            errors,
+            success_rate: if samples == 0 {
+                0.0
+            } else {
+                successes as f64 / samples as f64
+            },
+            min_ms,
+            max_ms,
+            mean_ms,
+            p50_ms: percentile(&millis, 0.50),
+            p95_ms: percentile(&millis, 0.95),
+            p99_ms: percentile(&millis, 0.99),
+        }
+    }
+}
+
+/// A full benchmark run against a single endpoint.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkReport {
+    pub provider_url: String,
+    pub per_op_stats: Vec<OpStats>,
+    pub samples: usize,
+}
+
+impl BenchmarkReport {
+    /// Serialize the report as pretty JSON.
+    pub fn to_json(&self) -> Result<String, BenchmarkError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| BenchmarkError::TransactionFetchError(e.to_string()))
+    }
+
+    /// Serialize the report as CSV, one row per operation.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(
+            "op,samples,successes,errors,success_rate,min_ms,max_ms,mean_ms,p50_ms,p95_ms,p99_ms\n",
+        );
+        for s in &self.per_op_stats {
+            out.push_str(&format!(
+                "{},{},{},{},{:.4},{:.3},{:.3},{:.3},{:.3},{:.3},{:.3}\n",
+                s.op,
+                s.samples,
+                s.successes,
+                s.errors,
+                s.success_rate,
+                s.min_ms,
+                s.max_ms,
+                s.mean_ms,
+                s.p50_ms,
+                s.p95_ms,
+                s.p99_ms,
+            ));
+        }
+        out
+    }
+
+    /// Render the report in the requested format.
+    pub fn render(&self, format: ReportFormat) -> Result<String, BenchmarkError> {
+        match format {
+            ReportFormat::Json => self.to_json(),
+            ReportFormat::Csv => Ok(self.to_csv()),
+        }
+    }
+}
+
+/// Runs each provider operation repeatedly and aggregates latency statistics.
+pub struct BenchmarkRunner {
+    samples: usize,
+}
+
+impl BenchmarkRunner {
+    pub fn new(samples: usize) -> Self {
+        Self {
+            samples: samples.max(1),
+        }
+    }
+
+    /// Benchmark the standard operations against `provider`.
+    pub async fn run(&self, provider_url: &str, provider: &Provider) -> BenchmarkReport {
+        let connect = self
+            .measure("connect", || async {
+                Provider::connect(provider_url)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| BenchmarkError::ProviderConnectionError(e.to_string()))
+            })
+            .await;
+
+        let block_height = self
+            .measure("block_height", || async {
+                provider
+                    .latest_block_height()
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| BenchmarkError::BlockHeightFetchError(e.to_string()))
+            })
+            .await;
+
+        let gas_price = self
+            .measure("gas_price", || async {
+                provider
+                    .latest_gas_price()
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| BenchmarkError::GasPriceFetchError(e.to_string()))
+            })
+            .await;
+
+        let tx_fetch = self
+            .measure("tx_fetch", || async {
+                let p_r = PaginationRequest {
+                    cursor: None,
+                    results: 10,
+                    direction: PageDirection::Backward,
+                };
+                provider
+                    .get_transactions(p_r)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| BenchmarkError::TransactionFetchError(e.to_string()))
+            })
+            .await;
+
+        BenchmarkReport {
+            provider_url: provider_url.to_string(),
+            per_op_stats: vec![connect, block_height, gas_price, tx_fetch],
+            samples: self.samples,
+        }
+    }
+
+    /// Run a single operation `self.samples` times and summarize it.
+    async fn measure<F, Fut>(&self, op: &str, mut call: F) -> OpStats
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<(), BenchmarkError>>,
+    {
+        let mut durations = Vec::new();
+        let mut errors = 0;
+        for _ in 0..self.samples {
+            let start = Instant::now();
+            match call().await {
+                Ok(()) => durations.push(start.elapsed()),
+                Err(_) => errors += 1,
+            }
+        }
+        OpStats::from_durations(op, &durations, errors)
+    }
+}
+
+fn duration_ms(d: &Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+/// Percentile over a sorted slice of milliseconds: the value at index
+/// `round((len - 1) * pct)`.
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let idx = (((sorted.len() - 1) as f64) * pct).round() as usize;
+    sorted[idx]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_rounds_to_nearest_index() {
+        let sorted: Vec<f64> = (1..=100).map(|n| n as f64).collect();
+        assert_eq!(percentile(&sorted, 0.50), 51.0); // 99*0.50=49.5 -> idx 50
+        assert_eq!(percentile(&sorted, 0.95), 95.0); // 99*0.95=94.05 -> idx 94
+        assert_eq!(percentile(&sorted, 0.99), 99.0); // 99*0.99=98.01 -> idx 98
+        assert_eq!(percentile(&[], 0.5), 0.0);
+    }
+
+    #[test]
+    fn op_stats_aggregate_durations_and_rates() {
+        let durations = [
+            Duration::from_millis(10),
+            Duration::from_millis(20),
+            Duration::from_millis(30),
+        ];
+        let stats = OpStats::from_durations("connect", &durations, 1);
+
+        assert_eq!(stats.op, "connect");
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.successes, 3);
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.success_rate, 0.75);
+        assert_eq!(stats.min_ms, 10.0);
+        assert_eq!(stats.max_ms, 30.0);
+        assert_eq!(stats.mean_ms, 20.0);
+        assert_eq!(stats.p50_ms, 20.0);
+    }
+
+    #[test]
+    fn empty_op_stats_are_zeroed() {
+        let stats = OpStats::from_durations("gas_price", &[], 0);
+        assert_eq!(stats.samples, 0);
+        assert_eq!(stats.success_rate, 0.0);
+        assert_eq!(stats.mean_ms, 0.0);
+    }
+
+    fn sample_report() -> BenchmarkReport {
+        BenchmarkReport {
+            provider_url: "node.example".to_string(),
+            per_op_stats: vec![OpStats::from_durations(
+                "connect",
+                &[Duration::from_millis(10)],
+                0,
+            )],
+            samples: 1,
+        }
+    }
+
+    #[test]
+    fn csv_has_header_and_row_per_op() {
+        let csv = sample_report().to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "op,samples,successes,errors,success_rate,min_ms,max_ms,mean_ms,p50_ms,p95_ms,p99_ms"
+        );
+        let row = lines.next().unwrap();
+        assert!(row.starts_with("connect,1,1,0,"));
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn json_round_trips_fields() {
+        let json = sample_report().to_json().unwrap();
+        assert!(json.contains("\"provider_url\": \"node.example\""));
+        assert!(json.contains("\"op\": \"connect\""));
+    }
+
+    #[test]
+    fn report_format_parses_case_insensitively() {
+        assert_eq!("JSON".parse::<ReportFormat>().unwrap(), ReportFormat::Json);
+        assert_eq!("csv".parse::<ReportFormat>().unwrap(), ReportFormat::Csv);
+        assert!("yaml".parse::<ReportFormat>().is_err());
+    }
+}